@@ -0,0 +1,145 @@
+//! Double-buffered stepping machinery for cellular automatons, built on top of [`GridIndex`]'s
+//! neighbor lookups. An [`Automaton`] owns a generation of cell values and a scratch buffer; each
+//! call to [`Automaton::step`] computes the whole next generation into the scratch buffer from a
+//! user-supplied rule, then swaps the buffers, so the rule never observes a partially-updated
+//! generation.
+
+use std::mem;
+
+use crate::{Grid, GridIndex};
+
+/// A cellular automaton: a generation of `T` cells addressed by a [`GridIndex`], stepped forward
+/// one generation at a time by a rule function.
+///
+/// # Examples
+///
+/// ```
+/// use ameda::{automaton, Grid};
+///
+/// let mut glider = Grid::filled(5, 5, false).unwrap();
+/// glider.set(1, true);
+/// glider.set(7, true);
+/// glider.set(10, true);
+/// glider.set(11, true);
+/// glider.set(12, true);
+///
+/// let mut life = automaton::Automaton::new(glider);
+/// life.step(automaton::life());
+/// assert_eq!(life.cells().iter().filter(|c| **c).count(), 5);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Automaton<T> {
+    index: GridIndex,
+    cells: Vec<T>,
+    buffer: Vec<T>,
+}
+
+impl<T> Automaton<T> {
+    /// Builds an automaton out of a [`Grid`]'s current generation.
+    pub fn new(grid: Grid<T>) -> Automaton<T> {
+        let (index, cells) = grid.into_parts();
+        let buffer = Vec::with_capacity(cells.len());
+        Automaton {
+            index: index,
+            cells: cells,
+            buffer: buffer,
+        }
+    }
+
+    /// Builds an automaton directly out of a `GridIndex` and the matching cell values, returning
+    /// `None` if `cells.len()` doesn't equal `index.cell_count()`.
+    pub fn from_cells(cells: Vec<T>, index: GridIndex) -> Option<Automaton<T>> {
+        if cells.len() == index.cell_count() {
+            let buffer = Vec::with_capacity(cells.len());
+            Some(Automaton {
+                index: index,
+                cells: cells,
+                buffer: buffer,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [`GridIndex`] the automaton is stepped over.
+    pub fn grid_index(&self) -> &GridIndex {
+        &self.index
+    }
+
+    /// Returns the current generation's cell values, in the same order as the underlying
+    /// `GridIndex`.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Advances the automaton by one generation. For every cell, `rule` is called with a
+    /// reference to the cell's current value and the values of its Moore neighborhood (the up to
+    /// eight cells surrounding it, honoring the `GridIndex`'s [`WrapMode`](crate::WrapMode)), and
+    /// its return value becomes the cell's value in the next generation.
+    ///
+    /// The next generation is built up in a scratch buffer and only swapped in once every cell
+    /// has been computed, so `rule` always sees the previous generation in full, never a mix of
+    /// old and new cells.
+    pub fn step<F>(&mut self, rule: F)
+        where F: Fn(&T, &[&T]) -> T
+    {
+        self.buffer.clear();
+        for i in 0..self.index.cell_count() {
+            let neighbors: Vec<&T> = self.index
+                .moore_neighbors(i)
+                .iter()
+                .map(|n| &self.cells[*n])
+                .collect();
+            self.buffer.push(rule(&self.cells[i], &neighbors));
+        }
+        mem::swap(&mut self.cells, &mut self.buffer);
+    }
+}
+
+/// Conway's Game of Life rule (B3/S23): a live cell with 2 or 3 live neighbors survives, a dead
+/// cell with exactly 3 live neighbors is born, every other cell dies or stays dead. Meant to be
+/// passed to [`Automaton::step`] over `bool` cells where `true` means alive.
+///
+/// Works equally well with a bounded or [`WrapMode::Toroidal`](crate::WrapMode::Toroidal)
+/// `GridIndex`, so the classic infinite-torus Life is just `GridIndex::new_wrapping`.
+pub fn life() -> impl Fn(&bool, &[&bool]) -> bool {
+    |alive, neighbors| {
+        let live_neighbors = neighbors.iter().filter(|n| ***n).count();
+        matches!((*alive, live_neighbors), (true, 2) | (true, 3) | (false, 3))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    #[test]
+    fn test_life_blinker() {
+        // A vertical blinker at the center of a 5x5 bounded grid oscillates to horizontal and
+        // back every generation.
+        let mut grid = Grid::filled(5, 5, false).unwrap();
+        grid.set(7, true);
+        grid.set(12, true);
+        grid.set(17, true);
+
+        let mut automaton = Automaton::new(grid);
+        automaton.step(life());
+        assert_eq!(automaton.cells(),
+                   &[false, false, false, false, false, false, false, false, false, false,
+                     false, true, true, true, false, false, false, false, false, false, false,
+                     false, false, false, false][..]);
+
+        automaton.step(life());
+        assert_eq!(automaton.cells(),
+                   &[false, false, false, false, false, false, false, true, false, false, false,
+                     false, true, false, false, false, false, true, false, false, false, false,
+                     false, false, false][..]);
+    }
+
+    #[test]
+    fn test_from_cells_rejects_mismatched_length() {
+        let index = GridIndex::new(4, 4).unwrap();
+        assert_eq!(Automaton::from_cells(vec![false; 15], index), None);
+    }
+}
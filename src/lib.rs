@@ -14,6 +14,81 @@
 //! assert_eq!(grid.bottom_row_indices(), &vec![56, 57, 58, 59, 60, 61, 62, 63]);
 //! ```
 
+use std::mem;
+
+pub mod automaton;
+pub use automaton::{Automaton, life};
+
+/// One of the eight compass directions a cell's neighbor can be in, used with
+/// [`GridIndex::neighbor`] in place of the old `"rt"`/`"dr"`/etc. string codes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Direction {
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+    Up,
+    UpRight,
+}
+
+impl Direction {
+    /// All eight directions, starting from the right and proceeding clockwise.
+    const MOORE: [Direction; 8] = [Direction::Right,
+                                    Direction::DownRight,
+                                    Direction::Down,
+                                    Direction::DownLeft,
+                                    Direction::Left,
+                                    Direction::UpLeft,
+                                    Direction::Up,
+                                    Direction::UpRight];
+
+    /// The four orthogonal directions, starting from the right and proceeding clockwise.
+    const VON_NEUMANN: [Direction; 4] =
+        [Direction::Right, Direction::Down, Direction::Left, Direction::Up];
+
+    /// The `(dx, dy)` offset this direction moves a coordinate by.
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+        }
+    }
+}
+
+/// Controls how [`GridIndex::neighbor`] (and the `rt_i`/`dn_i`/etc. wrappers) behave for
+/// cells sitting on the border of the grid.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum WrapMode {
+    /// Border cells simply have no neighbor past the edge; lookups past the edge return `None`.
+    Bounded,
+    /// The grid wraps around like a torus, so every cell, including those on the border, has
+    /// all eight neighbors.
+    Toroidal,
+}
+
+/// Controls the memory order [`GridIndex::xy_to_index`] (and everything built on top of it, such
+/// as [`GridIndex::neighbor`] and the row/column accessors) uses to turn an `(x, y)` coordinate
+/// into a flat index.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Order {
+    /// Cells are laid out a row at a time, so consecutive flat indices sweep across a row
+    /// (`grid_length * y + x`). This is the default, and what every method assumed before
+    /// `Order` was introduced.
+    RowMajor,
+    /// Cells are laid out a column at a time, so consecutive flat indices sweep down a column
+    /// (`grid_height * x + y`). Handy when the storage backing the grid is itself column
+    /// contiguous, e.g. for cheap `push_col`-style access.
+    ColumnMajor,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct GridIndex {
     grid_length: usize,
@@ -28,12 +103,15 @@ pub struct GridIndex {
     top_row_indices: Vec<usize>,
     bottom_row_indices: Vec<usize>,
     middle_indices: Vec<usize>,
+    wrap_mode: WrapMode,
+    order: Order,
 }
 
 impl GridIndex {
     /// Constructs a new 2D grid of cells that are `grid_length` cells wide and `grid_height`
     /// cells high. The total number of cells in the grid would be a product of both the
-    /// `grid_length` and `grid_height`.
+    /// `grid_length` and `grid_height`. Neighbor lookups past the border return `None`; use
+    /// [`GridIndex::new_wrapping`] for a grid that wraps around like a torus instead.
     ///
     /// # Examples
     ///
@@ -50,6 +128,60 @@ impl GridIndex {
     /// assert_eq!(GridIndex::new(1, 10), None);
     /// ```
     pub fn new(grid_length: usize, grid_height: usize) -> Option<GridIndex> {
+        GridIndex::new_with_options(grid_length, grid_height, WrapMode::Bounded, Order::RowMajor)
+    }
+
+    /// Constructs a new 2D grid, same as [`GridIndex::new`], except the grid wraps around like a
+    /// torus: a cell on the right edge has the leftmost cell of the same row as its right
+    /// neighbor, and so on for every edge and corner. This is handy for cellular automatons such
+    /// as Conway's Game of Life that are conventionally run on an infinite (or wrapping) plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::GridIndex;
+    ///
+    /// let grid = GridIndex::new_wrapping(8, 8).unwrap();
+    /// assert_eq!(grid.rt_i(7), Some(0));
+    /// assert_eq!(grid.up_i(0), Some(56));
+    /// ```
+    pub fn new_wrapping(grid_length: usize, grid_height: usize) -> Option<GridIndex> {
+        GridIndex::new_with_options(grid_length, grid_height, WrapMode::Toroidal, Order::RowMajor)
+    }
+
+    /// Constructs a new 2D grid, same as [`GridIndex::new`], except the flat indices are laid
+    /// out in the given [`Order`] instead of the default [`Order::RowMajor`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::{GridIndex, Order};
+    ///
+    /// let grid = GridIndex::new_with_order(4, 4, Order::ColumnMajor).unwrap();
+    /// assert_eq!(grid.col_cell_indexes(0), Some(vec![0, 1, 2, 3]));
+    /// ```
+    pub fn new_with_order(grid_length: usize,
+                           grid_height: usize,
+                           order: Order)
+                           -> Option<GridIndex> {
+        GridIndex::new_with_options(grid_length, grid_height, WrapMode::Bounded, order)
+    }
+
+    /// Constructs a new 2D grid combining [`GridIndex::new_wrapping`] and
+    /// [`GridIndex::new_with_order`]: it wraps around like a torus, with flat indices laid out in
+    /// the given [`Order`].
+    pub fn new_wrapping_with_order(grid_length: usize,
+                                    grid_height: usize,
+                                    order: Order)
+                                    -> Option<GridIndex> {
+        GridIndex::new_with_options(grid_length, grid_height, WrapMode::Toroidal, order)
+    }
+
+    fn new_with_options(grid_length: usize,
+                         grid_height: usize,
+                         wrap_mode: WrapMode,
+                         order: Order)
+                         -> Option<GridIndex> {
         match (grid_length, grid_height) {
             (x, y) if x > 1 && y > 1 && x < 512 && x < 512 => {
                 let total_indices = grid_length * grid_height;
@@ -67,8 +199,15 @@ impl GridIndex {
                     top_row_indices: vec![],
                     bottom_row_indices: vec![],
                     middle_indices: vec![],
+                    wrap_mode: wrap_mode,
+                    order: order,
                 };
 
+                grid.top_right_corner = grid.xy_to_index(grid_length - 1, 0).unwrap();
+                grid.bottom_left_corner = grid.xy_to_index(0, grid_height - 1).unwrap();
+                grid.bottom_right_corner =
+                    grid.xy_to_index(grid_length - 1, grid_height - 1).unwrap();
+
                 grid.top_row_indices = grid.row_indices(0);
                 grid.bottom_row_indices = grid.row_indices(grid_height - 1);
                 grid.left_column_indices = grid.column_indices(0);
@@ -80,6 +219,16 @@ impl GridIndex {
         }
     }
 
+    /// Returns the [`WrapMode`] this grid was constructed with.
+    pub fn wrap_mode(&self) -> WrapMode {
+        self.wrap_mode
+    }
+
+    /// Returns the [`Order`] this grid was constructed with.
+    pub fn order(&self) -> Order {
+        self.order
+    }
+
     /// Returns the number of cells in the grid
     ///
     /// # Example
@@ -131,56 +280,176 @@ impl GridIndex {
     }
 
     pub fn rt_i(&self, src_index: usize) -> Option<usize> {
-
-        self.neighbor_index(src_index, "rt")
+        self.neighbor(src_index, Direction::Right)
     }
 
     pub fn dr_i(&self, src_index: usize) -> Option<usize> {
-        self.neighbor_index(src_index, "dr")
+        self.neighbor(src_index, Direction::DownRight)
     }
 
     pub fn dn_i(&self, src_index: usize) -> Option<usize> {
-        self.neighbor_index(src_index, "dn")
+        self.neighbor(src_index, Direction::Down)
     }
 
     pub fn dl_i(&self, src_index: usize) -> Option<usize> {
-        self.neighbor_index(src_index, "dl")
+        self.neighbor(src_index, Direction::DownLeft)
     }
 
     pub fn lt_i(&self, src_index: usize) -> Option<usize> {
-        self.neighbor_index(src_index, "lt")
+        self.neighbor(src_index, Direction::Left)
     }
 
     pub fn ul_i(&self, src_index: usize) -> Option<usize> {
-        self.neighbor_index(src_index, "ul")
+        self.neighbor(src_index, Direction::UpLeft)
     }
 
     pub fn up_i(&self, src_index: usize) -> Option<usize> {
-        self.neighbor_index(src_index, "up")
+        self.neighbor(src_index, Direction::Up)
     }
 
     pub fn ur_i(&self, src_index: usize) -> Option<usize> {
-        self.neighbor_index(src_index, "ur")
+        self.neighbor(src_index, Direction::UpRight)
     }
 
+    /// Returns the neighbor of `src_index` in the given `direction`, or `None` if `src_index` is
+    /// out of bounds or has no neighbor there (the latter only in [`WrapMode::Bounded`] mode,
+    /// since every cell has all eight neighbors in [`WrapMode::Toroidal`] mode).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::{Direction, GridIndex};
+    ///
+    /// let grid = GridIndex::new(8, 8).unwrap();
+    /// assert_eq!(grid.neighbor(0, Direction::Right), Some(1));
+    /// assert_eq!(grid.neighbor(0, Direction::Left), None);
+    /// ```
+    pub fn neighbor(&self, src_index: usize, direction: Direction) -> Option<usize> {
+        if src_index >= self.total_indices {
+            return None;
+        }
 
-    fn row_indices(&self, row: usize) -> Vec<usize> {
-        let start_index = self.grid_length * row;
-        let end_index = (self.grid_length * (row + 1)) - 1;
+        match self.wrap_mode {
+            WrapMode::Toroidal => self.toroidal_neighbor(src_index, direction),
+            WrapMode::Bounded => self.bounded_neighbor(src_index, direction),
+        }
+    }
+
+    /// Returns every Moore neighbor (all eight compass directions) of `src_index` that exists,
+    /// starting from the right and proceeding clockwise. This is the primitive cellular
+    /// automatons such as Conway's Game of Life need to count live neighbors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::GridIndex;
+    ///
+    /// let grid = GridIndex::new(8, 8).unwrap();
+    /// assert_eq!(grid.moore_neighbors(0), vec![1, 9, 8]);
+    /// ```
+    pub fn moore_neighbors(&self, src_index: usize) -> Vec<usize> {
+        Direction::MOORE.iter().filter_map(|d| self.neighbor(src_index, *d)).collect()
+    }
 
-        let mut v = Vec::with_capacity(self.grid_length);
-        for i in start_index..(end_index + 1) {
-            v.push(i);
+    /// Returns every von Neumann neighbor (north/east/south/west only) of `src_index` that
+    /// exists, starting from the right and proceeding clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::GridIndex;
+    ///
+    /// let grid = GridIndex::new(8, 8).unwrap();
+    /// assert_eq!(grid.von_neumann_neighbors(0), vec![1, 8]);
+    /// ```
+    pub fn von_neumann_neighbors(&self, src_index: usize) -> Vec<usize> {
+        Direction::VON_NEUMANN.iter().filter_map(|d| self.neighbor(src_index, *d)).collect()
+    }
+
+    /// Converts an `(x, y)` coordinate into its flat index, or `None` if `x` or `y` is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::GridIndex;
+    ///
+    /// let grid = GridIndex::new(4, 4).unwrap();
+    /// assert_eq!(grid.xy_to_index(3, 3), Some(15));
+    /// assert_eq!(grid.xy_to_index(4, 0), None);
+    /// ```
+    pub fn xy_to_index(&self, x: usize, y: usize) -> Option<usize> {
+        if x >= self.grid_length || y >= self.grid_height {
+            None
+        } else {
+            Some(match self.order {
+                Order::RowMajor => (self.grid_length * y) + x,
+                Order::ColumnMajor => (self.grid_height * x) + y,
+            })
         }
-        v
     }
 
-    fn column_indices(&self, column: usize) -> Vec<usize> {
-        let mut v = Vec::with_capacity(self.grid_height);
-        for i in 0..self.grid_height {
-            v.push((self.grid_length * i) + column)
+    /// Converts a flat index into its `(x, y)` coordinate, or `None` if `index` is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::GridIndex;
+    ///
+    /// let grid = GridIndex::new(4, 4).unwrap();
+    /// assert_eq!(grid.index_to_xy(15), Some((3, 3)));
+    /// assert_eq!(grid.index_to_xy(16), None);
+    /// ```
+    pub fn index_to_xy(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.total_indices {
+            None
+        } else {
+            Some(match self.order {
+                Order::RowMajor => (index % self.grid_length, index / self.grid_length),
+                Order::ColumnMajor => (index / self.grid_height, index % self.grid_height),
+            })
+        }
+    }
+
+    /// Returns the flat indices of the `width` by `height` rectangular window whose top-left
+    /// corner is at `(col_start, row_start)`, in row-major order, or `None` if the window would
+    /// spill past the grid's borders.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::GridIndex;
+    ///
+    /// let grid = GridIndex::new(4, 4).unwrap();
+    /// assert_eq!(grid.subgrid_indices(1, 1, 2, 2), Some(vec![5, 6, 9, 10]));
+    /// assert_eq!(grid.subgrid_indices(3, 3, 2, 2), None);
+    /// ```
+    pub fn subgrid_indices(&self,
+                            col_start: usize,
+                            row_start: usize,
+                            width: usize,
+                            height: usize)
+                            -> Option<Vec<usize>> {
+        if (col_start + width) > self.grid_length || (row_start + height) > self.grid_height {
+            return None;
         }
-        v
+
+        let mut indices = Vec::with_capacity(width * height);
+        for row in row_start..(row_start + height) {
+            for col in col_start..(col_start + width) {
+                indices.push(self.xy_to_index(col, row).unwrap());
+            }
+        }
+        Some(indices)
+    }
+
+    fn row_indices(&self, row: usize) -> Vec<usize> {
+        (0..self.grid_length).map(|x| self.xy_to_index(x, row).unwrap()).collect()
+    }
+
+    fn column_indices(&self, column: usize) -> Vec<usize> {
+        (0..self.grid_height).map(|y| self.xy_to_index(column, y).unwrap()).collect()
     }
 
     fn middle_indices(&mut self) {
@@ -194,67 +463,28 @@ impl GridIndex {
         }
     }
 
-    fn neighbor_index(&self, src_index: usize, neighbor: &str) -> Option<usize> {
+    fn toroidal_neighbor(&self, src_index: usize, direction: Direction) -> Option<usize> {
+        let (dx, dy) = direction.delta();
+        let (x, y) = self.index_to_xy(src_index).unwrap();
 
-        let indices_to_check = match neighbor {
-            "rt" => (vec![&self.right_column_indices], Some(src_index + 1)),
-            "dr" => {
-                (vec![&self.right_column_indices, &self.bottom_row_indices],
-                 Some(src_index + self.grid_length + 1))
-            }
-            "dn" => (vec![&self.bottom_row_indices], Some(src_index + self.grid_length)),
-            "dl" => {
-                (vec![&self.left_column_indices, &self.bottom_row_indices],
-                 Some(src_index + self.grid_length - 1))
-            }
-            "lt" => {
-                (vec![&self.left_column_indices],
-                 {
-                     if src_index != 0 {
-                         Some(src_index - 1)
-                     } else {
-                         None
-                     }
-                 })
-            }
-            "ul" => {
-                (vec![&self.left_column_indices, &self.top_row_indices],
-                 {
-                     if src_index < (self.grid_length + 1) {
-                         None
-                     } else {
-                         Some(src_index - self.grid_length - 1)
-                     }
-                 })
-            }
-            "up" => {
-                (vec![&self.top_row_indices],
-                 {
-                     if src_index < self.grid_length {
-                         None
-                     } else {
-                         Some(src_index - self.grid_length)
-                     }
-                 })
-            }
-            "ur" => {
-                (vec![&self.right_column_indices, &self.top_row_indices],
-                 {
-                     if src_index < self.grid_length {
-                         None
-                     } else {
-                         Some(src_index - self.grid_length + 1)
-                     }
-                 })
-            }
-            _ => (vec![], None),
-        };
+        let grid_length = self.grid_length as isize;
+        let grid_height = self.grid_height as isize;
+        let new_x = ((x as isize) + dx + grid_length) % grid_length;
+        let new_y = ((y as isize) + dy + grid_height) % grid_height;
 
-        if src_index < self.total_indices &&
-           !indices_to_check.0.iter().any(|v| v.contains(&src_index)) {
-            indices_to_check.1
-        } else {
+        self.xy_to_index(new_x as usize, new_y as usize)
+    }
+
+    fn bounded_neighbor(&self, src_index: usize, direction: Direction) -> Option<usize> {
+        let (dx, dy) = direction.delta();
+        let (x, y) = self.index_to_xy(src_index).unwrap();
+        let new_x = (x as isize) + dx;
+        let new_y = (y as isize) + dy;
+
+        if new_x < 0 || new_y < 0 {
             None
+        } else {
+            self.xy_to_index(new_x as usize, new_y as usize)
         }
     }
 
@@ -272,6 +502,236 @@ impl GridIndex {
 //     TopRightCorner,
 // }
 
+/// A 2D grid that actually stores a value of type `T` for every cell, built on top of a
+/// [`GridIndex`] for all the index bookkeeping. Where `GridIndex` only tells you *which* cells
+/// are where, `Grid<T>` lets you keep the cell contents themselves in one place instead of
+/// maintaining a parallel `Vec<T>` by hand.
+///
+/// # Examples
+///
+/// ```
+/// use ameda::Grid;
+///
+/// let grid = Grid::filled(4, 4, 0).unwrap();
+/// assert_eq!(grid.cell_count(), 16);
+/// assert_eq!(grid.get(0), Some(&0));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Grid<T> {
+    index: GridIndex,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Constructs a new `Grid` that is `grid_length` cells wide and `grid_height` cells high,
+    /// with every cell initialized to a clone of `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::Grid;
+    ///
+    /// let grid = Grid::filled(8, 8, 'x').unwrap();
+    /// assert_eq!(grid.cell_count(), 64);
+    /// assert_eq!(grid.get(63), Some(&'x'));
+    ///
+    /// // Same size restrictions as `GridIndex::new` apply.
+    /// assert_eq!(Grid::filled(1, 10, 0), None);
+    /// ```
+    pub fn filled(grid_length: usize, grid_height: usize, value: T) -> Option<Grid<T>>
+        where T: Clone
+    {
+        let index = GridIndex::new(grid_length, grid_height)?;
+        let cells = vec![value; index.cell_count()];
+        Some(Grid { index: index, cells: cells })
+    }
+
+    /// Constructs a new `Grid` that is `grid_length` cells wide and `grid_height` cells high,
+    /// calling `f(x, y)` for every cell to produce its initial value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::Grid;
+    ///
+    /// let grid = Grid::from_fn(4, 4, |x, y| x + y).unwrap();
+    /// assert_eq!(grid.get_xy(3, 3), Some(&6));
+    /// ```
+    pub fn from_fn<F>(grid_length: usize, grid_height: usize, mut f: F) -> Option<Grid<T>>
+        where F: FnMut(usize, usize) -> T
+    {
+        let index = GridIndex::new(grid_length, grid_height)?;
+        let mut cells = Vec::with_capacity(index.cell_count());
+        for y in 0..grid_height {
+            for x in 0..grid_length {
+                cells.push(f(x, y));
+            }
+        }
+        Some(Grid { index: index, cells: cells })
+    }
+
+    /// Returns a reference to the underlying [`GridIndex`], for callers that need direct access
+    /// to the index bookkeeping alongside the stored cell values.
+    pub fn grid_index(&self) -> &GridIndex {
+        &self.index
+    }
+
+    /// Consumes the grid, returning its [`GridIndex`] and the owned cell values, e.g. to hand
+    /// off to an [`automaton::Automaton`](crate::automaton::Automaton).
+    pub fn into_parts(self) -> (GridIndex, Vec<T>) {
+        (self.index, self.cells)
+    }
+
+    /// Returns the number of cells in the grid.
+    pub fn cell_count(&self) -> usize {
+        self.index.cell_count()
+    }
+
+    /// Returns a reference to the value at `index`, or `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.cells.get(index)
+    }
+
+    /// Returns a mutable reference to the value at `index`, or `None` if `index` is out of
+    /// bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.cells.get_mut(index)
+    }
+
+    /// Returns a reference to the value at the `(x, y)` coordinate, or `None` if `x` or `y` is
+    /// out of bounds.
+    pub fn get_xy(&self, x: usize, y: usize) -> Option<&T> {
+        self.index.xy_to_index(x, y).and_then(|i| self.get(i))
+    }
+
+    /// Replaces the value at `index` with `value`, returning the previous value, or `None` if
+    /// `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: T) -> Option<T> {
+        if index >= self.cells.len() {
+            None
+        } else {
+            Some(mem::replace(&mut self.cells[index], value))
+        }
+    }
+
+    /// Returns the indices in the given row. 0-indexed. Delegates to
+    /// [`GridIndex::row_cell_indexes`].
+    pub fn row_cell_indexes(&self, row: usize) -> Option<Vec<usize>> {
+        self.index.row_cell_indexes(row)
+    }
+
+    /// Returns the indices in the given column. 0-indexed. Delegates to
+    /// [`GridIndex::col_cell_indexes`].
+    pub fn col_cell_indexes(&self, column: usize) -> Option<Vec<usize>> {
+        self.index.col_cell_indexes(column)
+    }
+
+    pub fn top_row_indices(&self) -> &Vec<usize> {
+        self.index.top_row_indices()
+    }
+
+    pub fn left_column_indices(&self) -> &Vec<usize> {
+        self.index.left_column_indices()
+    }
+
+    pub fn right_column_indices(&self) -> &Vec<usize> {
+        self.index.right_column_indices()
+    }
+
+    pub fn bottom_row_indices(&self) -> &Vec<usize> {
+        self.index.bottom_row_indices()
+    }
+
+    pub fn rt_i(&self, src_index: usize) -> Option<usize> {
+        self.index.rt_i(src_index)
+    }
+
+    pub fn dr_i(&self, src_index: usize) -> Option<usize> {
+        self.index.dr_i(src_index)
+    }
+
+    pub fn dn_i(&self, src_index: usize) -> Option<usize> {
+        self.index.dn_i(src_index)
+    }
+
+    pub fn dl_i(&self, src_index: usize) -> Option<usize> {
+        self.index.dl_i(src_index)
+    }
+
+    pub fn lt_i(&self, src_index: usize) -> Option<usize> {
+        self.index.lt_i(src_index)
+    }
+
+    pub fn ul_i(&self, src_index: usize) -> Option<usize> {
+        self.index.ul_i(src_index)
+    }
+
+    pub fn up_i(&self, src_index: usize) -> Option<usize> {
+        self.index.up_i(src_index)
+    }
+
+    pub fn ur_i(&self, src_index: usize) -> Option<usize> {
+        self.index.ur_i(src_index)
+    }
+
+    /// Returns the neighbor of `src_index` in the given direction. Delegates to
+    /// [`GridIndex::neighbor`].
+    pub fn neighbor(&self, src_index: usize, direction: Direction) -> Option<usize> {
+        self.index.neighbor(src_index, direction)
+    }
+
+    /// Returns every Moore neighbor of `src_index` that exists. Delegates to
+    /// [`GridIndex::moore_neighbors`].
+    pub fn moore_neighbors(&self, src_index: usize) -> Vec<usize> {
+        self.index.moore_neighbors(src_index)
+    }
+
+    /// Returns every von Neumann neighbor of `src_index` that exists. Delegates to
+    /// [`GridIndex::von_neumann_neighbors`].
+    pub fn von_neumann_neighbors(&self, src_index: usize) -> Vec<usize> {
+        self.index.von_neumann_neighbors(src_index)
+    }
+
+    /// Converts an `(x, y)` coordinate into its flat index. Delegates to
+    /// [`GridIndex::xy_to_index`].
+    pub fn xy_to_index(&self, x: usize, y: usize) -> Option<usize> {
+        self.index.xy_to_index(x, y)
+    }
+
+    /// Converts a flat index into its `(x, y)` coordinate. Delegates to
+    /// [`GridIndex::index_to_xy`].
+    pub fn index_to_xy(&self, index: usize) -> Option<(usize, usize)> {
+        self.index.index_to_xy(index)
+    }
+
+    /// Returns the flat indices of a rectangular window into the grid. Delegates to
+    /// [`GridIndex::subgrid_indices`].
+    pub fn subgrid_indices(&self,
+                            col_start: usize,
+                            row_start: usize,
+                            width: usize,
+                            height: usize)
+                            -> Option<Vec<usize>> {
+        self.index.subgrid_indices(col_start, row_start, width, height)
+    }
+
+    /// Follows the neighbor in the given `direction` from `src_index` and returns a reference to
+    /// the value stored there, or `None` if there is no such neighbor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ameda::{Direction, Grid};
+    ///
+    /// let grid = Grid::from_fn(4, 4, |x, y| x + y * 4).unwrap();
+    /// assert_eq!(grid.neighbor_value(0, Direction::Right), Some(&1));
+    /// assert_eq!(grid.neighbor_value(0, Direction::Left), None);
+    /// ```
+    pub fn neighbor_value(&self, src_index: usize, direction: Direction) -> Option<&T> {
+        self.index.neighbor(src_index, direction).and_then(|i| self.get(i))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -375,6 +835,139 @@ mod tests {
     }
 
 
+    #[test]
+    fn test_toroidal_neighbors() {
+        let g = GridIndex::new_wrapping(8, 8).unwrap();
+        assert_eq!(g.wrap_mode(), WrapMode::Toroidal);
+
+        // Every corner and edge cell has all eight neighbors.
+        assert_eq!(Some(7), g.lt_i(g.top_left_corner));
+        assert_eq!(Some(56), g.up_i(g.top_left_corner));
+        assert_eq!(Some(63), g.ul_i(g.top_left_corner));
+
+        assert_eq!(Some(0), g.rt_i(g.top_right_corner));
+        assert_eq!(Some(63), g.up_i(g.top_right_corner));
+        assert_eq!(Some(56), g.ur_i(g.top_right_corner));
+
+        assert_eq!(Some(63), g.lt_i(g.bottom_left_corner));
+        assert_eq!(Some(0), g.dn_i(g.bottom_left_corner));
+        assert_eq!(Some(7), g.dl_i(g.bottom_left_corner));
+
+        assert_eq!(Some(56), g.rt_i(g.bottom_right_corner));
+        assert_eq!(Some(7), g.dn_i(g.bottom_right_corner));
+        assert_eq!(Some(0), g.dr_i(g.bottom_right_corner));
+
+        // Interior cells behave exactly as they would in bounded mode.
+        let bounded = GridIndex::new(8, 8).unwrap();
+        for rnd_i in &bounded.middle_indices {
+            assert_eq!(bounded.rt_i(*rnd_i), g.rt_i(*rnd_i));
+            assert_eq!(bounded.dr_i(*rnd_i), g.dr_i(*rnd_i));
+            assert_eq!(bounded.dn_i(*rnd_i), g.dn_i(*rnd_i));
+            assert_eq!(bounded.dl_i(*rnd_i), g.dl_i(*rnd_i));
+            assert_eq!(bounded.lt_i(*rnd_i), g.lt_i(*rnd_i));
+            assert_eq!(bounded.ul_i(*rnd_i), g.ul_i(*rnd_i));
+            assert_eq!(bounded.up_i(*rnd_i), g.up_i(*rnd_i));
+            assert_eq!(bounded.ur_i(*rnd_i), g.ur_i(*rnd_i));
+        }
+    }
+
+    #[test]
+    fn test_bulk_neighbors() {
+        let g = GridIndex::new(8, 8).unwrap();
+        assert_eq!(g.moore_neighbors(0), vec![1, 9, 8]);
+        assert_eq!(g.von_neumann_neighbors(0), vec![1, 8]);
+
+        let middle = g.middle_indices[0];
+        assert_eq!(g.moore_neighbors(middle).len(), 8);
+        assert_eq!(g.von_neumann_neighbors(middle).len(), 4);
+
+        let wrapping = GridIndex::new_wrapping(8, 8).unwrap();
+        assert_eq!(wrapping.moore_neighbors(0).len(), 8);
+        assert_eq!(wrapping.von_neumann_neighbors(0).len(), 4);
+    }
+
+    #[test]
+    fn test_xy_conversion_and_subgrid() {
+        let g = GridIndex::new(4, 4).unwrap();
+
+        assert_eq!(g.xy_to_index(3, 3), Some(15));
+        assert_eq!(g.xy_to_index(4, 0), None);
+        assert_eq!(g.xy_to_index(0, 4), None);
+
+        assert_eq!(g.index_to_xy(15), Some((3, 3)));
+        assert_eq!(g.index_to_xy(0), Some((0, 0)));
+        assert_eq!(g.index_to_xy(16), None);
+
+        for i in 0..g.cell_count() {
+            let (x, y) = g.index_to_xy(i).unwrap();
+            assert_eq!(g.xy_to_index(x, y), Some(i));
+        }
+
+        assert_eq!(g.subgrid_indices(1, 1, 2, 2), Some(vec![5, 6, 9, 10]));
+        assert_eq!(g.subgrid_indices(0, 0, 4, 4), Some((0..16).collect()));
+        assert_eq!(g.subgrid_indices(3, 3, 2, 2), None);
+        assert_eq!(g.subgrid_indices(0, 0, 5, 1), None);
+    }
+
+    #[test]
+    fn test_column_major_order() {
+        let g = GridIndex::new_with_order(4, 4, Order::ColumnMajor).unwrap();
+        assert_eq!(g.order(), Order::ColumnMajor);
+
+        // Flat indices now sweep down a column first.
+        assert_eq!(g.xy_to_index(0, 0), Some(0));
+        assert_eq!(g.xy_to_index(0, 1), Some(1));
+        assert_eq!(g.xy_to_index(1, 0), Some(4));
+        assert_eq!(g.index_to_xy(4), Some((1, 0)));
+
+        assert_eq!(g.col_cell_indexes(0), Some(vec![0, 1, 2, 3]));
+        assert_eq!(g.row_cell_indexes(0), Some(vec![0, 4, 8, 12]));
+
+        for i in 0..g.cell_count() {
+            let (x, y) = g.index_to_xy(i).unwrap();
+            assert_eq!(g.xy_to_index(x, y), Some(i));
+        }
+
+        // Neighbor relationships are unaffected by storage order: (1, 1) is still right of
+        // (0, 1) and below (1, 0), regardless of how those coordinates map to flat indices.
+        let src = g.xy_to_index(1, 1).unwrap();
+        assert_eq!(g.rt_i(g.xy_to_index(0, 1).unwrap()), Some(src));
+        assert_eq!(g.dn_i(g.xy_to_index(1, 0).unwrap()), Some(src));
+
+        // Relabeling the flat indices doesn't change how many Moore neighbors exist in total.
+        let row_major = GridIndex::new(4, 4).unwrap();
+        let total_neighbors = |grid: &GridIndex| {
+            (0..grid.cell_count()).map(|i| grid.moore_neighbors(i).len()).sum::<usize>()
+        };
+        assert_eq!(total_neighbors(&g), total_neighbors(&row_major));
+
+        let wrapping = GridIndex::new_wrapping_with_order(4, 4, Order::ColumnMajor).unwrap();
+        for i in 0..wrapping.cell_count() {
+            assert_eq!(wrapping.moore_neighbors(i).len(), 8);
+        }
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::filled(4, 4, 0).unwrap();
+        assert_eq!(grid.cell_count(), 16);
+        assert_eq!(grid.get(0), Some(&0));
+        assert_eq!(grid.get(16), None);
+
+        let mut grid = Grid::from_fn(4, 4, |x, y| x + (y * 4)).unwrap();
+        assert_eq!(grid.get_xy(3, 3), Some(&15));
+        assert_eq!(grid.get_xy(4, 0), None);
+        assert_eq!(grid.neighbor_value(0, Direction::Right), Some(&1));
+        assert_eq!(grid.neighbor_value(0, Direction::Left), None);
+
+        assert_eq!(grid.set(0, 100), Some(0));
+        assert_eq!(grid.get(0), Some(&100));
+        *grid.get_mut(0).unwrap() = 200;
+        assert_eq!(grid.get(0), Some(&200));
+
+        assert_eq!(Grid::filled(1, 10, 0), None);
+    }
+
     // type Index = usize;
     // type Size = usize;
 